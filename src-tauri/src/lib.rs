@@ -9,6 +9,179 @@ pub struct ServerState(pub Mutex<Option<CommandChild>>);
 
 pub struct AppLifecycleState {
     pub is_quitting: AtomicBool,
+    /// Set once the backend has answered a health check successfully, as
+    /// opposed to merely having a live sidecar process.
+    pub is_ready: AtomicBool,
+    /// Set by `stop_server` so the supervisor treats the resulting
+    /// termination as intentional instead of respawning it. Cleared by
+    /// `start_server`.
+    pub stop_requested: AtomicBool,
+    /// Guards against spawning more than one supervisor at a time. Unlike
+    /// `ServerState`, which only holds a child once the sidecar has actually
+    /// been spawned (and goes back to `None` during restart backoff), this
+    /// flips true for the whole lifetime of `supervise_server`, so a second
+    /// concurrent `start_server` call can't race it into launching another.
+    pub is_supervising: AtomicBool,
+}
+
+/// Path polled on the backend to determine readiness.
+const HEALTH_CHECK_PATH: &str = "/health";
+/// Upper bound on how long we'll wait for the backend to become healthy.
+const READINESS_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Resolved backend host/port, populated by `start_server` (with a free
+/// ephemeral port picked at launch) so two app instances don't
+/// collide on a hardcoded port and the address can't drift out of sync
+/// between the sidecar args, the `api_*` commands, and the custom protocol.
+pub struct BackendConfig {
+    host: Mutex<String>,
+    port: Mutex<u16>,
+}
+
+impl Default for BackendConfig {
+    fn default() -> Self {
+        BackendConfig {
+            host: Mutex::new("127.0.0.1".to_string()),
+            port: Mutex::new(9527),
+        }
+    }
+}
+
+impl BackendConfig {
+    fn base_url(&self) -> String {
+        format!("http://{}:{}", self.host.lock().unwrap(), self.port.lock().unwrap())
+    }
+
+    fn port(&self) -> u16 {
+        *self.port.lock().unwrap()
+    }
+
+    fn set_port(&self, port: u16) {
+        *self.port.lock().unwrap() = port;
+    }
+}
+
+/// Bind an ephemeral port to let the OS pick a free one, then release it
+/// immediately so the sidecar can bind it in turn. Small TOCTOU window: if
+/// another process takes the port first, the sidecar exits and the supervisor
+/// respawns it on the same port; a new port is only picked by `start_server`.
+fn find_free_port() -> Result<u16, String> {
+    std::net::TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .map(|addr| addr.port())
+        .map_err(|e| format!("Failed to find a free port: {}", e))
+}
+
+/// Resolve the backend's base URL so the frontend and custom-protocol
+/// handler stay in sync with whatever port `start_server` picked.
+#[tauri::command]
+fn backend_address(config: tauri::State<'_, BackendConfig>) -> String {
+    config.base_url()
+}
+
+/// Tracks the abort handle of every in-flight `api_stream` task, keyed by
+/// `event_id`, so a stream can be cancelled from the frontend instead of
+/// running until the backend closes the connection on its own.
+pub struct StreamRegistry(pub Mutex<HashMap<String, tokio::task::AbortHandle>>);
+
+/// Upper (exclusive) bound in milliseconds of each latency histogram bucket,
+/// plus an implicit overflow bucket for anything at or above the last one.
+const LATENCY_BUCKETS_MS: [u64; 5] = [50, 100, 250, 500, 1000];
+/// How often `run()`'s background task emits a `proxy-metrics` snapshot.
+const METRICS_EMIT_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Lightweight, always-on visibility into how the app is using the backend:
+/// request/error counts per method and per path prefix, in-flight stream
+/// count, and a bucketed latency histogram. Updated by every `api_*`
+/// command and exposed to the frontend via the `proxy_metrics` command.
+#[derive(Default)]
+pub struct ProxyMetrics {
+    total_requests: std::sync::atomic::AtomicU64,
+    total_errors: std::sync::atomic::AtomicU64,
+    in_flight_streams: std::sync::atomic::AtomicI64,
+    by_method: Mutex<HashMap<String, (u64, u64)>>,
+    by_path_prefix: Mutex<HashMap<String, u64>>,
+    latency_buckets: [std::sync::atomic::AtomicU64; LATENCY_BUCKETS_MS.len() + 1],
+}
+
+impl ProxyMetrics {
+    /// First path segment (e.g. `/api/campaigns/123` -> `/api`), used to
+    /// bucket counts without exploding into one entry per unique path.
+    fn path_prefix(path: &str) -> String {
+        let trimmed = path.split('?').next().unwrap_or(path);
+        match trimmed.trim_start_matches('/').split('/').next() {
+            Some(seg) if !seg.is_empty() => format!("/{}", seg),
+            _ => "/".to_string(),
+        }
+    }
+
+    fn record_request(&self, method: &str, path: &str, elapsed: std::time::Duration, is_error: bool) {
+        self.total_requests.fetch_add(1, Ordering::Relaxed);
+        if is_error {
+            self.total_errors.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut by_method = self.by_method.lock().unwrap();
+        let entry = by_method.entry(method.to_string()).or_insert((0, 0));
+        entry.0 += 1;
+        if is_error {
+            entry.1 += 1;
+        }
+        drop(by_method);
+
+        *self.by_path_prefix.lock().unwrap().entry(Self::path_prefix(path)).or_insert(0) += 1;
+
+        let elapsed_ms = elapsed.as_millis() as u64;
+        let bucket = LATENCY_BUCKETS_MS.iter().position(|&bound| elapsed_ms < bound)
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+        self.latency_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn stream_started(&self) {
+        self.in_flight_streams.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn stream_ended(&self) {
+        self.in_flight_streams.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> serde_json::Value {
+        let by_method: serde_json::Value = self.by_method.lock().unwrap().iter()
+            .map(|(method, (requests, errors))| {
+                (method.clone(), serde_json::json!({ "requests": requests, "errors": errors }))
+            })
+            .collect();
+        let by_path_prefix: serde_json::Value = self.by_path_prefix.lock().unwrap().iter()
+            .map(|(prefix, count)| (prefix.clone(), serde_json::json!(count)))
+            .collect();
+        let mut latency_histogram_ms = serde_json::Map::new();
+        for (i, bound) in LATENCY_BUCKETS_MS.iter().enumerate() {
+            latency_histogram_ms.insert(
+                format!("under_{}", bound),
+                serde_json::json!(self.latency_buckets[i].load(Ordering::Relaxed)),
+            );
+        }
+        latency_histogram_ms.insert(
+            "over".to_string(),
+            serde_json::json!(self.latency_buckets[LATENCY_BUCKETS_MS.len()].load(Ordering::Relaxed)),
+        );
+
+        serde_json::json!({
+            "total_requests": self.total_requests.load(Ordering::Relaxed),
+            "total_errors": self.total_errors.load(Ordering::Relaxed),
+            "in_flight_streams": self.in_flight_streams.load(Ordering::Relaxed),
+            "by_method": by_method,
+            "by_path_prefix": by_path_prefix,
+            "latency_histogram_ms": latency_histogram_ms,
+        })
+    }
+}
+
+/// Snapshot the built-in proxy metrics so the UI can surface backend
+/// health/latency without scraping logs.
+#[tauri::command]
+fn proxy_metrics(metrics: tauri::State<'_, ProxyMetrics>) -> serde_json::Value {
+    metrics.snapshot()
 }
 
 /// HTTP client for proxying requests to backend (with redirect following enabled)
@@ -28,150 +201,238 @@ impl HttpClient {
 #[tauri::command]
 async fn api_get(
     client: tauri::State<'_, HttpClient>,
+    config: tauri::State<'_, BackendConfig>,
+    metrics: tauri::State<'_, ProxyMetrics>,
     path: String,
 ) -> Result<serde_json::Value, String> {
-    let url = format!("http://127.0.0.1:9527{}", path);
+    let url = format!("{}{}", config.base_url(), path);
     println!("[Proxy] GET {}", url);
+    let start = std::time::Instant::now();
+
+    let result = async {
+        let response = client.0
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            return Err(format!("HTTP {}: {}", status, text));
+        }
 
-    let response = client.0
-        .get(&url)
-        .send()
-        .await
-        .map_err(|e| format!("Request failed: {}", e))?;
-
-    let status = response.status();
-    if !status.is_success() {
-        let text = response.text().await.unwrap_or_default();
-        return Err(format!("HTTP {}: {}", status, text));
-    }
+        response
+            .json::<serde_json::Value>()
+            .await
+            .map_err(|e| format!("Failed to parse JSON: {}", e))
+    }.await;
 
-    response
-        .json::<serde_json::Value>()
-        .await
-        .map_err(|e| format!("Failed to parse JSON: {}", e))
+    metrics.record_request("GET", &path, start.elapsed(), result.is_err());
+    result
 }
 
 /// Proxy a POST request to the backend
 #[tauri::command]
 async fn api_post(
     client: tauri::State<'_, HttpClient>,
+    config: tauri::State<'_, BackendConfig>,
+    metrics: tauri::State<'_, ProxyMetrics>,
     path: String,
     body: Option<serde_json::Value>,
 ) -> Result<serde_json::Value, String> {
-    let url = format!("http://127.0.0.1:9527{}", path);
+    let url = format!("{}{}", config.base_url(), path);
     println!("[Proxy] POST {}", url);
+    let start = std::time::Instant::now();
 
-    let mut request = client.0.post(&url);
-    if let Some(b) = body {
-        request = request.json(&b);
-    }
+    let result = async {
+        let mut request = client.0.post(&url);
+        if let Some(b) = body {
+            request = request.json(&b);
+        }
 
-    let response = request
-        .send()
-        .await
-        .map_err(|e| format!("Request failed: {}", e))?;
+        let response = request
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
 
-    let status = response.status();
-    if !status.is_success() {
-        let text = response.text().await.unwrap_or_default();
-        return Err(format!("HTTP {}: {}", status, text));
-    }
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            return Err(format!("HTTP {}: {}", status, text));
+        }
+
+        response
+            .json::<serde_json::Value>()
+            .await
+            .map_err(|e| format!("Failed to parse JSON: {}", e))
+    }.await;
 
-    response
-        .json::<serde_json::Value>()
-        .await
-        .map_err(|e| format!("Failed to parse JSON: {}", e))
+    metrics.record_request("POST", &path, start.elapsed(), result.is_err());
+    result
 }
 
 /// Proxy a PUT request to the backend
 #[tauri::command]
 async fn api_put(
     client: tauri::State<'_, HttpClient>,
+    config: tauri::State<'_, BackendConfig>,
+    metrics: tauri::State<'_, ProxyMetrics>,
     path: String,
     body: Option<serde_json::Value>,
 ) -> Result<serde_json::Value, String> {
-    let url = format!("http://127.0.0.1:9527{}", path);
+    let url = format!("{}{}", config.base_url(), path);
     println!("[Proxy] PUT {}", url);
+    let start = std::time::Instant::now();
 
-    let mut request = client.0.put(&url);
-    if let Some(b) = body {
-        request = request.json(&b);
-    }
+    let result = async {
+        let mut request = client.0.put(&url);
+        if let Some(b) = body {
+            request = request.json(&b);
+        }
 
-    let response = request
-        .send()
-        .await
-        .map_err(|e| format!("Request failed: {}", e))?;
+        let response = request
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
 
-    let status = response.status();
-    if !status.is_success() {
-        let text = response.text().await.unwrap_or_default();
-        return Err(format!("HTTP {}: {}", status, text));
-    }
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            return Err(format!("HTTP {}: {}", status, text));
+        }
+
+        response
+            .json::<serde_json::Value>()
+            .await
+            .map_err(|e| format!("Failed to parse JSON: {}", e))
+    }.await;
 
-    response
-        .json::<serde_json::Value>()
-        .await
-        .map_err(|e| format!("Failed to parse JSON: {}", e))
+    metrics.record_request("PUT", &path, start.elapsed(), result.is_err());
+    result
 }
 
 /// Proxy a PATCH request to the backend
 #[tauri::command]
 async fn api_patch(
     client: tauri::State<'_, HttpClient>,
+    config: tauri::State<'_, BackendConfig>,
+    metrics: tauri::State<'_, ProxyMetrics>,
     path: String,
     body: Option<serde_json::Value>,
 ) -> Result<serde_json::Value, String> {
-    let url = format!("http://127.0.0.1:9527{}", path);
+    let url = format!("{}{}", config.base_url(), path);
     println!("[Proxy] PATCH {}", url);
+    let start = std::time::Instant::now();
 
-    let mut request = client.0.patch(&url);
-    if let Some(b) = body {
-        request = request.json(&b);
-    }
+    let result = async {
+        let mut request = client.0.patch(&url);
+        if let Some(b) = body {
+            request = request.json(&b);
+        }
 
-    let response = request
-        .send()
-        .await
-        .map_err(|e| format!("Request failed: {}", e))?;
+        let response = request
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
 
-    let status = response.status();
-    if !status.is_success() {
-        let text = response.text().await.unwrap_or_default();
-        return Err(format!("HTTP {}: {}", status, text));
-    }
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            return Err(format!("HTTP {}: {}", status, text));
+        }
 
-    response
-        .json::<serde_json::Value>()
-        .await
-        .map_err(|e| format!("Failed to parse JSON: {}", e))
+        response
+            .json::<serde_json::Value>()
+            .await
+            .map_err(|e| format!("Failed to parse JSON: {}", e))
+    }.await;
+
+    metrics.record_request("PATCH", &path, start.elapsed(), result.is_err());
+    result
 }
 
 /// Proxy a DELETE request to the backend
 #[tauri::command]
 async fn api_delete(
     client: tauri::State<'_, HttpClient>,
+    config: tauri::State<'_, BackendConfig>,
+    metrics: tauri::State<'_, ProxyMetrics>,
     path: String,
 ) -> Result<serde_json::Value, String> {
-    let url = format!("http://127.0.0.1:9527{}", path);
+    let url = format!("{}{}", config.base_url(), path);
     println!("[Proxy] DELETE {}", url);
+    let start = std::time::Instant::now();
+
+    let result = async {
+        let response = client.0
+            .delete(&url)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            return Err(format!("HTTP {}: {}", status, text));
+        }
 
-    let response = client.0
-        .delete(&url)
-        .send()
-        .await
-        .map_err(|e| format!("Request failed: {}", e))?;
+        response
+            .json::<serde_json::Value>()
+            .await
+            .map_err(|e| format!("Failed to parse JSON: {}", e))
+    }.await;
 
-    let status = response.status();
-    if !status.is_success() {
-        let text = response.text().await.unwrap_or_default();
-        return Err(format!("HTTP {}: {}", status, text));
+    metrics.record_request("DELETE", &path, start.elapsed(), result.is_err());
+    result
+}
+
+/// One parsed Server-Sent-Event frame: the joined `data:` lines plus the
+/// optional `event:`/`id:` fields.
+struct SseFrame {
+    event: Option<String>,
+    id: Option<String>,
+    data: Option<String>,
+}
+
+/// Parse a single SSE frame (the lines between two `\n\n`/`\r\n\r\n`
+/// terminators). Comment lines starting with `:` are skipped; multiple
+/// `data:` lines are joined with `\n`, stripping one optional leading space.
+fn parse_sse_frame(frame: &str) -> SseFrame {
+    let mut event = None;
+    let mut id = None;
+    let mut data_lines: Vec<&str> = Vec::new();
+
+    for line in frame.lines() {
+        if line.starts_with(':') {
+            continue;
+        } else if let Some(rest) = line.strip_prefix("data:") {
+            data_lines.push(rest.strip_prefix(' ').unwrap_or(rest));
+        } else if let Some(rest) = line.strip_prefix("event:") {
+            event = Some(rest.strip_prefix(' ').unwrap_or(rest).to_string());
+        } else if let Some(rest) = line.strip_prefix("id:") {
+            id = Some(rest.strip_prefix(' ').unwrap_or(rest).to_string());
+        }
+    }
+
+    SseFrame {
+        event,
+        id,
+        data: (!data_lines.is_empty()).then(|| data_lines.join("\n")),
     }
+}
 
-    response
-        .json::<serde_json::Value>()
-        .await
-        .map_err(|e| format!("Failed to parse JSON: {}", e))
+/// Find the earliest SSE event terminator (`\r\n\r\n` or `\n\n`) in `buf`,
+/// returning `(frame_end, terminator_len)`.
+fn find_sse_terminator(buf: &[u8]) -> Option<(usize, usize)> {
+    let crlf = buf.windows(4).position(|w| w == b"\r\n\r\n").map(|pos| (pos, 4));
+    let lf = buf.windows(2).position(|w| w == b"\n\n").map(|pos| (pos, 2));
+    match (crlf, lf) {
+        (Some(a), Some(b)) => Some(if a.0 <= b.0 { a } else { b }),
+        (Some(a), None) => Some(a),
+        (None, Some(b)) => Some(b),
+        (None, None) => None,
+    }
 }
 
 /// Proxy a streaming request to the backend and emit events
@@ -179,16 +440,21 @@ async fn api_delete(
 async fn api_stream(
     app: AppHandle,
     client: tauri::State<'_, HttpClient>,
+    config: tauri::State<'_, BackendConfig>,
+    registry: tauri::State<'_, StreamRegistry>,
+    metrics: tauri::State<'_, ProxyMetrics>,
     path: String,
     method: Option<String>,
     body: Option<serde_json::Value>,
     event_id: String,
+    parse_sse: Option<bool>,
 ) -> Result<(), String> {
-    let url = format!("http://127.0.0.1:9527{}", path);
+    let url = format!("{}{}", config.base_url(), path);
     println!("[Proxy] STREAM {} to event {}", url, event_id);
+    let start = std::time::Instant::now();
 
     let method = method.unwrap_or_else(|| "GET".to_string()).to_uppercase();
-    
+
     let mut request = match method.as_str() {
         "GET" => client.0.get(&url),
         "POST" => client.0.post(&url),
@@ -199,99 +465,411 @@ async fn api_stream(
         request = request.json(&b);
     }
 
-    let mut response = request
-        .send()
-        .await
-        .map_err(|e| format!("Request failed: {}", e))?;
+    let mut response = match request.send().await {
+        Ok(response) => response,
+        Err(e) => {
+            metrics.record_request("STREAM", &path, start.elapsed(), true);
+            return Err(format!("Request failed: {}", e));
+        }
+    };
 
     let status = response.status();
     if !status.is_success() {
         let text = response.text().await.unwrap_or_default();
+        metrics.record_request("STREAM", &path, start.elapsed(), true);
         return Err(format!("HTTP {}: {}", status, text));
     }
 
-    tauri::async_runtime::spawn(async move {
+    metrics.record_request("STREAM", &path, start.elapsed(), false);
+    metrics.stream_started();
+
+    let task_event_id = event_id.clone();
+    let parse_sse = parse_sse.unwrap_or(false);
+    // Hold the registry lock across the spawn so a task that finishes at once
+    // can't try to deregister itself before its handle has been inserted.
+    let mut streams = registry.0.lock().unwrap();
+    let handle = tauri::async_runtime::spawn(async move {
+        let mut sse_buffer: Vec<u8> = Vec::new();
+
         loop {
             match response.chunk().await {
                 Ok(Some(chunk)) => {
-                    let text = String::from_utf8_lossy(&chunk).to_string();
-                    let _ = app.emit(&format!("stream-event-{}", event_id), serde_json::json!({
-                        "type": "chunk",
-                        "data": text
-                    }));
+                    if parse_sse {
+                        sse_buffer.extend_from_slice(&chunk);
+                        while let Some((frame_end, terminator_len)) = find_sse_terminator(&sse_buffer) {
+                            let frame_bytes: Vec<u8> = sse_buffer.drain(..frame_end + terminator_len).collect();
+                            let frame = String::from_utf8_lossy(&frame_bytes[..frame_end]).to_string();
+                            let parsed = parse_sse_frame(&frame);
+                            // Per SSE semantics, an event block with no `data` line
+                            // (comment-only heartbeats, bare `event:`/`id:` fields)
+                            // isn't dispatched — don't forward it as a spurious event.
+                            if parsed.data.is_some() {
+                                let _ = app.emit(&format!("stream-event-{}", task_event_id), serde_json::json!({
+                                    "type": "sse",
+                                    "event": parsed.event,
+                                    "id": parsed.id,
+                                    "data": parsed.data
+                                }));
+                            }
+                        }
+                    } else {
+                        let text = String::from_utf8_lossy(&chunk).to_string();
+                        let _ = app.emit(&format!("stream-event-{}", task_event_id), serde_json::json!({
+                            "type": "chunk",
+                            "data": text
+                        }));
+                    }
                 }
                 Ok(None) => break, // End of stream
                 Err(e) => {
-                     let _ = app.emit(&format!("stream-event-{}", event_id), serde_json::json!({
+                     let _ = app.emit(&format!("stream-event-{}", task_event_id), serde_json::json!({
                         "type": "error",
                         "message": e.to_string()
                     }));
+                    if app.state::<StreamRegistry>().0.lock().unwrap().remove(&task_event_id).is_some() {
+                        app.state::<ProxyMetrics>().stream_ended();
+                    }
                     return;
                 }
             }
         }
-        
-        let _ = app.emit(&format!("stream-event-{}", event_id), serde_json::json!({
+
+        if parse_sse && !sse_buffer.is_empty() {
+            let frame = String::from_utf8_lossy(&sse_buffer).to_string();
+            let parsed = parse_sse_frame(&frame);
+            if parsed.data.is_some() {
+                let _ = app.emit(&format!("stream-event-{}", task_event_id), serde_json::json!({
+                    "type": "sse",
+                    "event": parsed.event,
+                    "id": parsed.id,
+                    "data": parsed.data
+                }));
+            }
+        }
+
+        let _ = app.emit(&format!("stream-event-{}", task_event_id), serde_json::json!({
             "type": "done"
         }));
+        if app.state::<StreamRegistry>().0.lock().unwrap().remove(&task_event_id).is_some() {
+            app.state::<ProxyMetrics>().stream_ended();
+        }
     });
 
+    streams.insert(event_id, handle.abort_handle());
+    drop(streams);
+
     Ok(())
 }
 
-/// Start the Python FastAPI server as a sidecar process
+/// Cancel an in-flight stream started via `api_stream`: aborts its task
+/// (dropping the upstream response along with it) and emits a final
+/// `{"type":"cancelled"}` event, distinct from a stream that ends naturally
+/// via `done` or `error`.
 #[tauri::command]
-async fn start_server(app: AppHandle, state: tauri::State<'_, ServerState>) -> Result<String, String> {
-    // Check if server is already running
-    if state.0.lock().unwrap().is_some() {
-        return Ok("Server already running".to_string());
+fn api_cancel_stream(
+    app: AppHandle,
+    registry: tauri::State<'_, StreamRegistry>,
+    metrics: tauri::State<'_, ProxyMetrics>,
+    event_id: String,
+) -> Result<(), String> {
+    let handle = registry.0.lock().unwrap().remove(&event_id)
+        .ok_or_else(|| format!("No in-flight stream for event_id {}", event_id))?;
+
+    handle.abort();
+    metrics.stream_ended();
+    let _ = app.emit(&format!("stream-event-{}", event_id), serde_json::json!({
+        "type": "cancelled"
+    }));
+
+    Ok(())
+}
+
+/// Forward a request to the backend through the `backend://` custom URI-scheme
+/// protocol, returning the raw response bytes with the original status code
+/// and `Content-Type` instead of forcing everything through JSON. The whole
+/// upstream body is read before the response is sent; it is not streamed
+/// to the webview chunk by chunk.
+///
+/// This lets the frontend hit the sidecar with plain `fetch()`/`<img src>`/
+/// downloads for non-JSON payloads (files, CSV exports, images, plain-text
+/// error bodies) that the `api_*` commands above can't carry.
+fn handle_backend_protocol(
+    client: reqwest::Client,
+    base_url: String,
+    request: tauri::http::Request<Vec<u8>>,
+    responder: tauri::UriSchemeResponder,
+) {
+    tauri::async_runtime::spawn(async move {
+        let path_and_query = request
+            .uri()
+            .path_and_query()
+            .map(|pq| pq.as_str())
+            .unwrap_or("/");
+        // A bare `backend://health` has no real path component, so
+        // `path_and_query()` reports the implicit root "/" — drop it rather
+        // than emitting a spurious trailing slash.
+        let path_and_query = if path_and_query == "/" { "" } else { path_and_query };
+        // `backend://<path>` is parsed by the webview as host = first path
+        // segment, so the real path lives in `uri().host()` + the rest.
+        let host = request.uri().host().unwrap_or_default();
+        let url = format!("{}/{}{}", base_url, host, path_and_query);
+
+        let method = match reqwest::Method::from_bytes(request.method().as_str().as_bytes()) {
+            Ok(method) => method,
+            Err(e) => {
+                respond_with_error(responder, format!("Invalid method: {}", e));
+                return;
+            }
+        };
+
+        let mut builder = client.request(method, &url);
+        for (name, value) in request.headers() {
+            if name.as_str().eq_ignore_ascii_case("host") {
+                continue;
+            }
+            builder = builder.header(name, value);
+        }
+        builder = builder.body(request.body().clone());
+
+        let upstream = match builder.send().await {
+            Ok(response) => response,
+            Err(e) => {
+                respond_with_error(responder, format!("Request failed: {}", e));
+                return;
+            }
+        };
+
+        let status = upstream.status().as_u16();
+        let content_type = upstream
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .cloned();
+
+        let body = match upstream.bytes().await {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                respond_with_error(responder, format!("Failed to read response body: {}", e));
+                return;
+            }
+        };
+
+        let mut response_builder = tauri::http::Response::builder().status(status);
+        if let Some(content_type) = content_type {
+            response_builder = response_builder.header(
+                tauri::http::header::CONTENT_TYPE,
+                content_type.as_bytes(),
+            );
+        }
+
+        match response_builder.body(body.to_vec()) {
+            Ok(response) => responder.respond(response),
+            Err(e) => respond_with_error(responder, format!("Failed to build response: {}", e)),
+        }
+    });
+}
+
+fn respond_with_error(responder: tauri::UriSchemeResponder, message: String) {
+    eprintln!("[backend://] {}", message);
+    let response = tauri::http::Response::builder()
+        .status(502)
+        .body(message.into_bytes())
+        .unwrap();
+    responder.respond(response);
+}
+
+/// Poll `health_path` on the backend with exponential backoff until it
+/// answers successfully or `READINESS_TIMEOUT` elapses. Emits `server-ready`
+/// (and flips `AppLifecycleState.is_ready`) on success, or `server-unhealthy`
+/// on timeout.
+async fn wait_for_server_ready(app: AppHandle, client: reqwest::Client, base_url: &str, health_path: &str) {
+    let url = format!("{}{}", base_url, health_path);
+    let deadline = std::time::Instant::now() + READINESS_TIMEOUT;
+    let mut backoff = std::time::Duration::from_millis(100);
+    const MAX_BACKOFF: std::time::Duration = std::time::Duration::from_secs(2);
+
+    loop {
+        match client.get(&url).send().await {
+            Ok(response) if response.status().is_success() => {
+                let state = app.state::<AppLifecycleState>();
+                state.is_ready.store(true, Ordering::Relaxed);
+                println!("[Server] Ready ({})", url);
+                let _ = app.emit("server-ready", ());
+                return;
+            }
+            _ => {}
+        }
+
+        if std::time::Instant::now() >= deadline {
+            eprintln!("[Server] Readiness check timed out after {:?}", READINESS_TIMEOUT);
+            let _ = app.emit("server-unhealthy", ());
+            return;
+        }
+
+        tokio::time::sleep(backoff).await;
+        backoff = std::cmp::min(backoff * 2, MAX_BACKOFF);
     }
+}
+
+/// Maximum number of consecutive restart attempts before giving up.
+const MAX_RESTART_ATTEMPTS: u32 = 5;
+/// Initial delay before the first restart attempt.
+const RESTART_BACKOFF_BASE: std::time::Duration = std::time::Duration::from_millis(500);
+/// Ceiling the capped exponential backoff never exceeds.
+const RESTART_BACKOFF_MAX: std::time::Duration = std::time::Duration::from_secs(30);
+/// How long a restarted sidecar must stay up before the backoff/attempt
+/// counter resets, so a flaky crash loop doesn't get treated the same as a
+/// server that's been healthy for hours.
+const HEALTHY_RESET_WINDOW: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Spawn the sidecar once, forward its stdout/stderr as events, and return
+/// once it terminates (or the channel closes).
+async fn spawn_and_monitor_sidecar(
+    app: &AppHandle,
+    state: &tauri::State<'_, ServerState>,
+) -> Result<(), String> {
+    use tauri_plugin_shell::process::CommandEvent;
+
+    let config = app.state::<BackendConfig>();
+    let port = config.port().to_string();
 
     let sidecar = app
         .shell()
         .sidecar("search-ads-server")
         .map_err(|e| format!("Failed to create sidecar command: {}", e))?
-        .args(["--port", "9527", "--host", "127.0.0.1"]);
+        .args(["--port", &port, "--host", "127.0.0.1"]);
 
     let (mut rx, child) = sidecar
         .spawn()
         .map_err(|e| format!("Failed to spawn sidecar: {}", e))?;
 
-    // Store child process for later shutdown
     *state.0.lock().unwrap() = Some(child);
+    app.state::<AppLifecycleState>().is_ready.store(false, Ordering::Relaxed);
 
-    // Monitor stdout/stderr in background
-    let app_handle = app.clone();
+    let client = app.state::<HttpClient>().0.clone();
+    let base_url = config.base_url();
+    let app_for_readiness = app.clone();
     tauri::async_runtime::spawn(async move {
-        use tauri_plugin_shell::process::CommandEvent;
-        while let Some(event) = rx.recv().await {
-            match event {
-                CommandEvent::Stdout(line) => {
-                    let output = String::from_utf8_lossy(&line);
-                    println!("[Server] {}", output);
-                    let _ = app_handle.emit("server-log", output.to_string());
-                }
-                CommandEvent::Stderr(line) => {
-                    let output = String::from_utf8_lossy(&line);
-                    eprintln!("[Server Error] {}", output);
-                    let _ = app_handle.emit("server-error", output.to_string());
-                }
-                CommandEvent::Terminated(status) => {
-                    println!("[Server] Process terminated with status: {:?}", status);
-                    let _ = app_handle.emit("server-terminated", format!("{:?}", status));
-                    break;
-                }
-                _ => {}
+        wait_for_server_ready(app_for_readiness, client, &base_url, HEALTH_CHECK_PATH).await;
+    });
+
+    while let Some(event) = rx.recv().await {
+        match event {
+            CommandEvent::Stdout(line) => {
+                let output = String::from_utf8_lossy(&line);
+                println!("[Server] {}", output);
+                let _ = app.emit("server-log", output.to_string());
             }
+            CommandEvent::Stderr(line) => {
+                let output = String::from_utf8_lossy(&line);
+                eprintln!("[Server Error] {}", output);
+                let _ = app.emit("server-error", output.to_string());
+            }
+            CommandEvent::Terminated(status) => {
+                println!("[Server] Process terminated with status: {:?}", status);
+                let _ = app.emit("server-terminated", format!("{:?}", status));
+                break;
+            }
+            _ => {}
         }
-    });
+    }
 
-    Ok("Server started successfully".to_string())
+    *state.0.lock().unwrap() = None;
+    app.state::<AppLifecycleState>().is_ready.store(false, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Supervise the sidecar for the lifetime of the app: on an unexpected
+/// termination (the app isn't quitting), respawn it with capped exponential
+/// backoff, up to `MAX_RESTART_ATTEMPTS`, emitting `server-restarting` /
+/// `server-failed` so the UI can reflect reconnection state.
+///
+/// Runs under `AppLifecycleState.is_supervising`, which the caller
+/// (`start_server`) must already hold; cleared here on every exit so a
+/// later `start_server` call can supervise again.
+async fn supervise_server(app: AppHandle) {
+    let mut attempt: u32 = 0;
+    let mut backoff = RESTART_BACKOFF_BASE;
+
+    loop {
+        let state = app.state::<ServerState>();
+        let started_at = std::time::Instant::now();
+
+        if let Err(e) = spawn_and_monitor_sidecar(&app, &state).await {
+            eprintln!("[Server] {}", e);
+            let _ = app.emit("server-failed", e);
+            break;
+        }
+
+        let lifecycle = app.state::<AppLifecycleState>();
+        if lifecycle.is_quitting.load(Ordering::Relaxed) || lifecycle.stop_requested.load(Ordering::Relaxed) {
+            break;
+        }
+
+        if started_at.elapsed() >= HEALTHY_RESET_WINDOW {
+            attempt = 0;
+            backoff = RESTART_BACKOFF_BASE;
+        }
+
+        attempt += 1;
+        if attempt > MAX_RESTART_ATTEMPTS {
+            eprintln!("[Server] Giving up after {} restart attempts", MAX_RESTART_ATTEMPTS);
+            let _ = app.emit("server-failed", format!("Exceeded {} restart attempts", MAX_RESTART_ATTEMPTS));
+            break;
+        }
+
+        let _ = app.emit("server-restarting", attempt);
+        tokio::time::sleep(backoff).await;
+        backoff = std::cmp::min(backoff * 2, RESTART_BACKOFF_MAX);
+
+        // A stop or quit requested during the backoff sleep cancels the restart.
+        if lifecycle.is_quitting.load(Ordering::Relaxed) || lifecycle.stop_requested.load(Ordering::Relaxed) {
+            break;
+        }
+    }
+
+    app.state::<AppLifecycleState>().is_supervising.store(false, Ordering::Relaxed);
+}
+
+/// Start the Python FastAPI server as a supervised sidecar process.
+///
+/// Resolves as soon as the supervisor task has been spawned, before the
+/// sidecar itself is launched and *not* once it's actually serving (a spawn
+/// failure is reported via `server-failed`) — readiness is deliberately event-based (`server-ready`
+/// / `server-unhealthy`, and `server_status.ready`) rather than something
+/// this command awaits, because the supervisor it hands off to runs for the
+/// lifetime of the app and keeps respawning/re-probing across crashes; a
+/// call that only resolved on first readiness would hang across a restart
+/// loop instead of reporting the supervised start it actually performed.
+#[tauri::command]
+async fn start_server(app: AppHandle) -> Result<String, String> {
+    // Claim the supervisor slot; if one is already running (or mid-restart
+    // backoff with `ServerState` transiently `None`), don't start a second.
+    let lifecycle = app.state::<AppLifecycleState>();
+    if lifecycle.is_supervising.compare_exchange(
+        false, true, Ordering::Relaxed, Ordering::Relaxed,
+    ).is_err() {
+        return Ok("Server supervisor already running".to_string());
+    }
+
+    let port = find_free_port()?;
+    app.state::<BackendConfig>().set_port(port);
+    lifecycle.stop_requested.store(false, Ordering::Relaxed);
+
+    tauri::async_runtime::spawn(supervise_server(app.clone()));
+
+    Ok("Server supervisor started; awaiting readiness via server-ready/server-unhealthy".to_string())
 }
 
 /// Stop the Python server gracefully
 #[tauri::command]
-async fn stop_server(state: tauri::State<'_, ServerState>) -> Result<String, String> {
+async fn stop_server(
+    state: tauri::State<'_, ServerState>,
+    lifecycle: tauri::State<'_, AppLifecycleState>,
+) -> Result<String, String> {
+    // Tell the supervisor this termination is intentional so it doesn't
+    // respawn the sidecar we're about to kill.
+    lifecycle.stop_requested.store(true, Ordering::Relaxed);
+
     let mut guard = state.0.lock().unwrap();
     if let Some(mut child) = guard.take() {
         // Send shutdown command via stdin
@@ -306,15 +884,29 @@ async fn stop_server(state: tauri::State<'_, ServerState>) -> Result<String, Str
     }
 }
 
+/// Server status as seen from the frontend: whether the sidecar process
+/// exists, and separately whether it has passed a health check yet.
+#[derive(serde::Serialize)]
+struct ServerStatus {
+    running: bool,
+    ready: bool,
+}
+
 /// Get server status
 #[tauri::command]
-fn server_status(state: tauri::State<'_, ServerState>) -> bool {
-    state.0.lock().unwrap().is_some()
+fn server_status(
+    state: tauri::State<'_, ServerState>,
+    lifecycle: tauri::State<'_, AppLifecycleState>,
+) -> ServerStatus {
+    ServerStatus {
+        running: state.0.lock().unwrap().is_some(),
+        ready: lifecycle.is_ready.load(Ordering::Relaxed),
+    }
 }
 
 /// Internal function to start the server (not a command)
-async fn do_start_server(app: AppHandle, state: tauri::State<'_, ServerState>) -> Result<String, String> {
-    start_server(app, state).await
+async fn do_start_server(app: AppHandle) -> Result<String, String> {
+    start_server(app).await
 }
 
 /// Setup function to run when app starts
@@ -323,13 +915,26 @@ pub fn run() {
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_http::init())
         .manage(ServerState(Mutex::new(None)))
-        .manage(AppLifecycleState { is_quitting: AtomicBool::new(false) })
+        .manage(AppLifecycleState {
+            is_quitting: AtomicBool::new(false),
+            is_ready: AtomicBool::new(false),
+            stop_requested: AtomicBool::new(false),
+            is_supervising: AtomicBool::new(false),
+        })
         .manage(HttpClient::new())
+        .manage(StreamRegistry(Mutex::new(HashMap::new())))
+        .manage(ProxyMetrics::default())
+        .manage(BackendConfig::default())
         .invoke_handler(tauri::generate_handler![
             start_server, stop_server, server_status,
             api_get, api_post, api_put, api_patch, api_delete,
-            api_stream
+            api_stream, api_cancel_stream, proxy_metrics, backend_address
         ])
+        .register_asynchronous_uri_scheme_protocol("backend", |app, request, responder| {
+            let client = app.state::<HttpClient>().0.clone();
+            let base_url = app.state::<BackendConfig>().base_url();
+            handle_backend_protocol(client, base_url, request, responder);
+        })
         .setup(|app| {
             // Auto-start server on app launch
             let handle = app.handle().clone();
@@ -337,12 +942,22 @@ pub fn run() {
                 // Small delay to ensure app is ready
                 std::thread::sleep(std::time::Duration::from_millis(500));
 
-                let state = handle.state::<ServerState>();
-                match do_start_server(handle.clone(), state).await {
+                match do_start_server(handle.clone()).await {
                     Ok(msg) => println!("Server startup: {}", msg),
                     Err(e) => eprintln!("Failed to start server: {}", e),
                 }
             });
+
+            // Periodically broadcast a proxy metrics snapshot
+            let metrics_handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                loop {
+                    tokio::time::sleep(METRICS_EMIT_INTERVAL).await;
+                    let snapshot = metrics_handle.state::<ProxyMetrics>().snapshot();
+                    let _ = metrics_handle.emit("proxy-metrics", snapshot);
+                }
+            });
+
             Ok(())
         })
         .build(tauri::generate_context!())
@@ -391,4 +1006,3 @@ pub fn run() {
             }
         });
 }
-